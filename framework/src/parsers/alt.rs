@@ -0,0 +1,40 @@
+use super::*;
+
+pub trait ParserAltExt: Sized + Parser {
+    /// Tries `self`, falling back to `other` if `self` produces a
+    /// `Recoverable` error. A `Fatal` error from `self` propagates
+    /// immediately without trying `other`.
+    fn or<P2>(self, other: P2) -> Or<Self, P2>
+    where
+        P2: Parser<Output = Self::Output>,
+    {
+        Or {
+            first: self,
+            second: other,
+        }
+    }
+}
+
+impl<P: Parser> ParserAltExt for P {}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Or<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Parser for Or<A, B>
+where
+    A: Parser,
+    B: Parser<Output = A::Output>,
+{
+    type Output = A::Output;
+
+    fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
+        match self.first.parse(input) {
+            Ok(x) => Ok(x),
+            Err(e) if e.is_fatal() => Err(e),
+            Err(_) => self.second.parse(input),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+mod alt;
+mod multi;
+mod whitespace;
+
+pub use alt::*;
+pub use multi::*;
+pub use whitespace::ws;
+
+/// The result of attempting a parse: either the parsed value together with
+/// the unconsumed remainder of the input, or an error.
+pub type ParseResult<'s, O> = Result<(O, &'s str), ParseError>;
+
+/// An error produced by a parser, carrying a human-readable description.
+///
+/// `Recoverable` errors are the normal "this alternative didn't match"
+/// signal: combinators like `or`/`repeat`/`sep_by` treat them as a cue to
+/// backtrack or stop. `Fatal` errors mean a parser has committed to an
+/// alternative and hit a genuine syntax error partway through, so the
+/// failure should propagate all the way up instead of being swallowed.
+/// Use [`cut`](ParserExt::cut) to promote a `Recoverable` error to `Fatal`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Recoverable(String),
+    Fatal(String),
+}
+
+impl ParseError {
+    /// Constructs a recoverable error, the normal case for "didn't match".
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError::Recoverable(message.into())
+    }
+
+    pub fn fatal(message: impl Into<String>) -> Self {
+        ParseError::Fatal(message.into())
+    }
+
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, ParseError::Fatal(_))
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            ParseError::Recoverable(message) | ParseError::Fatal(message) => message,
+        }
+    }
+
+    fn into_fatal(self) -> Self {
+        ParseError::Fatal(self.message().to_owned())
+    }
+}
+
+/// Parses a prefix of the input, producing a value and the remaining input.
+pub trait Parser {
+    type Output;
+
+    fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output>;
+}
+
+pub trait ParserExt: Sized + Parser {
+    /// Promotes any `Recoverable` error produced by the parser into a
+    /// `Fatal` one, so that repetition and alternation combinators stop
+    /// and propagate instead of treating it as "didn't match".
+    fn cut(self) -> Cut<Self> {
+        Cut { parser: self }
+    }
+}
+
+impl<P: Parser> ParserExt for P {}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Cut<P> {
+    parser: P,
+}
+
+impl<P: Parser> Parser for Cut<P> {
+    type Output = P::Output;
+
+    fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
+        self.parser.parse(input).map_err(ParseError::into_fatal)
+    }
+}
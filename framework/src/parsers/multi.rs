@@ -15,6 +15,43 @@ pub trait ParserMultiExt: Sized + Parser {
         }
     }
 
+    /// Like [`sep_by`](Self::sep_by), but succeeds with an empty `Vec` instead
+    /// of erroring when the parser can't be applied even once.
+    fn sep_by0<S>(self, separator: S) -> SepBy0<Self, S>
+    where
+        S: Parser,
+    {
+        SepBy0 {
+            parser: self,
+            separator,
+        }
+    }
+
+    /// Repeatedly applies the parser, interspersing applications of `separator`.
+    /// Fails if parser cannot be applied at least once. Equivalent to
+    /// [`sep_by`](Self::sep_by), named to mirror [`sep_by0`](Self::sep_by0).
+    fn sep_by1<S>(self, separator: S) -> SepBy<Self, S>
+    where
+        S: Parser,
+    {
+        self.sep_by(separator)
+    }
+
+    /// Repeatedly applies the parser, interspersing applications of `separator`,
+    /// stopping once `max` elements have been parsed. Fails unless at least
+    /// `min` elements were parsed.
+    fn sep_by_m_n<S>(self, min: usize, max: usize, separator: S) -> SepByMN<Self, S>
+    where
+        S: Parser,
+    {
+        SepByMN {
+            parser: self,
+            separator,
+            min,
+            max,
+        }
+    }
+
     /// Repeatedly applies the parser, repeatedly invoking `func` with the
     /// output value, updating the accumulator which starts out as `initial`.
     fn fold<A, F>(self, initial: A, func: F) -> Fold<Self, A, F>
@@ -52,6 +89,56 @@ pub trait ParserMultiExt: Sized + Parser {
     fn many_n<const N: usize>(self) -> Many<Self, N> {
         Many { parser: self }
     }
+
+    /// Applies the parser exactly `n` times, collecting the outputs into a
+    /// `Vec`. Fails if fewer than `n` applications succeed.
+    fn count(self, n: usize) -> Count<Self> {
+        Count { parser: self, n }
+    }
+
+    /// Repeatedly applies the parser until `end` succeeds, returning the
+    /// collected outputs along with `end`'s output. `end` is tried before
+    /// each application of `self`, so it may match on the first attempt.
+    /// Fails if `self` fails before `end` matches.
+    fn many_till<E>(self, end: E) -> ManyTill<Self, E>
+    where
+        E: Parser,
+    {
+        ManyTill { parser: self, end }
+    }
+
+    /// Greedily applies the parser up to `max` times, collecting the outputs
+    /// into a `Vec`. Fails if fewer than `min` applications succeed.
+    fn many_m_n(self, min: usize, max: usize) -> ManyMN<Self> {
+        ManyMN {
+            parser: self,
+            min,
+            max,
+        }
+    }
+
+    /// Like [`fold`](Self::fold), but fails if the parser never applies
+    /// successfully even once.
+    fn fold_many1<A, F>(self, initial: A, func: F) -> FoldMany1<Self, A, F>
+    where
+        A: Clone,
+        F: Fn(A, Self::Output) -> A,
+    {
+        FoldMany1 {
+            parser: self,
+            initial,
+            func,
+        }
+    }
+
+    /// Like [`sep_by`](Self::sep_by), but uses a run of whitespace (rather
+    /// than a fixed separator) as the delimiter, and trims leading and
+    /// trailing whitespace around the whole list.
+    fn tokens_sep_by_ws(self) -> TokensSepByWs<Self> {
+        TokensSepByWs {
+            inner: self.sep_by(super::whitespace::ws1()),
+        }
+    }
 }
 
 impl<P: Parser> ParserMultiExt for P {}
@@ -62,6 +149,20 @@ pub struct SepBy<P, S> {
     separator: S,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct SepBy0<P, S> {
+    parser: P,
+    separator: S,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SepByMN<P, S> {
+    parser: P,
+    separator: S,
+    min: usize,
+    max: usize,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Fold<P, A, F> {
     parser: P,
@@ -86,6 +187,37 @@ pub struct Many<P, const N: usize> {
     parser: P,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct Count<P> {
+    parser: P,
+    n: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ManyTill<P, E> {
+    parser: P,
+    end: E,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ManyMN<P> {
+    parser: P,
+    min: usize,
+    max: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FoldMany1<P, A, F> {
+    parser: P,
+    initial: A,
+    func: F,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TokensSepByWs<P> {
+    inner: SepBy<P, super::whitespace::Ws1>,
+}
+
 impl<P, S> Parser for SepBy<P, S>
 where
     P: Parser,
@@ -100,6 +232,40 @@ where
         loop {
             let after_sep = match self.separator.parse(remainder) {
                 Ok((_, after_sep)) => after_sep,
+                Err(e) if e.is_fatal() => return Err(e),
+                Err(_) => return Ok((elements, remainder)),
+            };
+            match self.parser.parse(after_sep) {
+                Ok((element, after_value)) => {
+                    remainder = after_value;
+                    elements.push(element);
+                }
+                Err(e) if e.is_fatal() => return Err(e),
+                Err(_) => return Ok((elements, remainder)),
+            };
+        }
+    }
+}
+
+impl<P, S> Parser for SepBy0<P, S>
+where
+    P: Parser,
+    S: Parser,
+{
+    type Output = Vec<P::Output>;
+
+    fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
+        let (element, mut remainder) = match self.parser.parse(input) {
+            Ok(x) => x,
+            Err(e) if e.is_fatal() => return Err(e),
+            Err(_) => return Ok((Vec::new(), input)),
+        };
+        let mut elements = Vec::new();
+        elements.push(element);
+        loop {
+            let after_sep = match self.separator.parse(remainder) {
+                Ok((_, after_sep)) => after_sep,
+                Err(e) if e.is_fatal() => return Err(e),
                 Err(_) => return Ok((elements, remainder)),
             };
             match self.parser.parse(after_sep) {
@@ -107,12 +273,72 @@ where
                     remainder = after_value;
                     elements.push(element);
                 }
+                Err(e) if e.is_fatal() => return Err(e),
                 Err(_) => return Ok((elements, remainder)),
             };
         }
     }
 }
 
+impl<P, S> Parser for SepByMN<P, S>
+where
+    P: Parser,
+    S: Parser,
+{
+    type Output = Vec<P::Output>;
+
+    fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
+        if self.max == 0 {
+            return if self.min == 0 {
+                Ok((Vec::new(), input))
+            } else {
+                Err(ParseError::new(
+                    "sep_by_m_n: max is 0 but min is greater than 0",
+                ))
+            };
+        }
+
+        let (element, mut remainder) = match self.parser.parse(input) {
+            Ok(x) => x,
+            Err(e) => {
+                return if self.min == 0 && !e.is_fatal() {
+                    Ok((Vec::new(), input))
+                } else {
+                    Err(e)
+                }
+            }
+        };
+        let mut elements = Vec::new();
+        elements.push(element);
+
+        while elements.len() < self.max {
+            let after_sep = match self.separator.parse(remainder) {
+                Ok((_, after_sep)) => after_sep,
+                Err(e) if e.is_fatal() => return Err(e),
+                Err(_) => break,
+            };
+            match self.parser.parse(after_sep) {
+                Ok((element, after_value)) => {
+                    remainder = after_value;
+                    elements.push(element);
+                }
+                Err(e) if e.is_fatal() => return Err(e),
+                Err(_) => break,
+            };
+        }
+
+        if elements.len() >= self.min {
+            Ok((elements, remainder))
+        } else {
+            Err(ParseError::new(format!(
+                "sep_by_m_n: expected at least {} elements, got {}",
+                self.min,
+                elements.len()
+            )))
+        }
+    }
+}
+
 impl<P, A, F> Parser for Fold<P, A, F>
 where
     P: Parser,
@@ -124,9 +350,15 @@ where
     fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
         let mut accumulator = self.initial.clone();
         let mut remainder = input;
-        while let Ok((value, new_remainder)) = self.parser.parse(remainder) {
-            accumulator = (self.func)(accumulator, value);
-            remainder = new_remainder;
+        loop {
+            match self.parser.parse(remainder) {
+                Ok((value, new_remainder)) => {
+                    accumulator = (self.func)(accumulator, value);
+                    remainder = new_remainder;
+                }
+                Err(e) if e.is_fatal() => return Err(e),
+                Err(_) => break,
+            }
         }
         Ok((accumulator, remainder))
     }
@@ -143,9 +375,40 @@ where
     fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
         let mut accumulator = self.initial.clone();
         let mut remainder = input;
-        while let Ok((value, new_remainder)) = self.parser.parse(remainder) {
-            (self.func)(&mut accumulator, value);
-            remainder = new_remainder;
+        loop {
+            match self.parser.parse(remainder) {
+                Ok((value, new_remainder)) => {
+                    (self.func)(&mut accumulator, value);
+                    remainder = new_remainder;
+                }
+                Err(e) if e.is_fatal() => return Err(e),
+                Err(_) => break,
+            }
+        }
+        Ok((accumulator, remainder))
+    }
+}
+
+impl<P, A, F> Parser for FoldMany1<P, A, F>
+where
+    P: Parser,
+    A: Clone,
+    F: Fn(A, P::Output) -> A,
+{
+    type Output = A;
+
+    fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
+        let (value, mut remainder) = self.parser.parse(input)?;
+        let mut accumulator = (self.func)(self.initial.clone(), value);
+        loop {
+            match self.parser.parse(remainder) {
+                Ok((value, new_remainder)) => {
+                    accumulator = (self.func)(accumulator, value);
+                    remainder = new_remainder;
+                }
+                Err(e) if e.is_fatal() => return Err(e),
+                Err(_) => break,
+            }
         }
         Ok((accumulator, remainder))
     }
@@ -162,9 +425,15 @@ where
             Ok(x) => x,
             Err(e) => return Err(e),
         };
-        while let Ok((value, new_remainder)) = self.parser.parse(remainder) {
-            last_value = value;
-            remainder = new_remainder;
+        loop {
+            match self.parser.parse(remainder) {
+                Ok((value, new_remainder)) => {
+                    last_value = value;
+                    remainder = new_remainder;
+                }
+                Err(e) if e.is_fatal() => return Err(e),
+                Err(_) => break,
+            }
         }
         Ok((last_value, remainder))
     }
@@ -210,3 +479,81 @@ impl<P: Parser, const N: usize> Parser for Many<P, N> {
         Ok((result, remainder))
     }
 }
+
+impl<P: Parser> Parser for Count<P> {
+    type Output = Vec<P::Output>;
+
+    fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
+        let mut elements = Vec::with_capacity(self.n);
+        let mut remainder = input;
+        for _ in 0..self.n {
+            let (value, new_remainder) = self.parser.parse(remainder)?;
+            remainder = new_remainder;
+            elements.push(value);
+        }
+        Ok((elements, remainder))
+    }
+}
+
+impl<P: Parser> Parser for ManyMN<P> {
+    type Output = Vec<P::Output>;
+
+    fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
+        let mut elements = Vec::new();
+        let mut remainder = input;
+        while elements.len() < self.max {
+            match self.parser.parse(remainder) {
+                Ok((value, new_remainder)) => {
+                    remainder = new_remainder;
+                    elements.push(value);
+                }
+                Err(e) if e.is_fatal() => return Err(e),
+                Err(_) => break,
+            }
+        }
+
+        if elements.len() >= self.min {
+            Ok((elements, remainder))
+        } else {
+            Err(ParseError::new(format!(
+                "many_m_n: expected at least {} elements, got {}",
+                self.min,
+                elements.len()
+            )))
+        }
+    }
+}
+
+impl<P, E> Parser for ManyTill<P, E>
+where
+    P: Parser,
+    E: Parser,
+{
+    type Output = (Vec<P::Output>, E::Output);
+
+    fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
+        let mut elements = Vec::new();
+        let mut remainder = input;
+        loop {
+            match self.end.parse(remainder) {
+                Ok((end, after_end)) => return Ok(((elements, end), after_end)),
+                Err(e) if e.is_fatal() => return Err(e),
+                Err(_) => {}
+            }
+            let (value, new_remainder) = self.parser.parse(remainder)?;
+            elements.push(value);
+            remainder = new_remainder;
+        }
+    }
+}
+
+impl<P: Parser> Parser for TokensSepByWs<P> {
+    type Output = Vec<P::Output>;
+
+    fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
+        let (_, input) = ws().parse(input)?;
+        let (elements, remainder) = self.inner.parse(input)?;
+        let (_, remainder) = ws().parse(remainder)?;
+        Ok((elements, remainder))
+    }
+}
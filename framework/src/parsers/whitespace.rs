@@ -0,0 +1,41 @@
+use super::*;
+
+/// Consumes a run of zero or more ASCII whitespace characters. Always
+/// succeeds, even if there is no whitespace to consume.
+pub fn ws() -> Ws {
+    Ws
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Ws;
+
+impl Parser for Ws {
+    type Output = ();
+
+    fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
+        let remainder = input.trim_start_matches(|c: char| c.is_ascii_whitespace());
+        Ok(((), remainder))
+    }
+}
+
+/// Consumes a run of one or more ASCII whitespace characters. Fails if the
+/// input doesn't start with whitespace.
+pub(super) fn ws1() -> Ws1 {
+    Ws1
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Ws1;
+
+impl Parser for Ws1 {
+    type Output = ();
+
+    fn parse<'s>(&self, input: &'s str) -> ParseResult<'s, Self::Output> {
+        let remainder = input.trim_start_matches(|c: char| c.is_ascii_whitespace());
+        if remainder.len() == input.len() {
+            Err(ParseError::new("expected whitespace"))
+        } else {
+            Ok(((), remainder))
+        }
+    }
+}